@@ -31,16 +31,64 @@ impl Chunk {
         &self.data
     }
 
-    pub fn from_data(data: Vec<u8>) -> Self {
+    pub fn message_id(&self) -> u64 {
+        self.message_id
+    }
+
+    pub fn from_data(message_id: u64, data: Vec<u8>) -> Self {
         Self {
             length: (24 + data.len()) as u32,
             chunk_x: Self::encode_chunk_x(0, 1),
-            message_id: 11, // FIXME: caller must generate ID
+            message_id,
             message_length: data.len() as u64, // TODO: check if this includes header or not
             data
         }
     }
 
+    /// Default maximum size (in bytes) of a chunk's data payload when splitting a message,
+    /// chosen so that a full chunk (24-byte header plus data) stays below 0x8000 bytes.
+    pub const DEFAULT_MAX_CHUNK_DATA: usize = 30000;
+
+    /// Splits `payload` for `message_id` into one or more `Chunk`s, none of whose data
+    /// exceeds `max_chunk_data` bytes. The first chunk carries the total chunk count in its
+    /// `chunk_x` field; every subsequent chunk carries its 0-indexed position within the
+    /// message instead (1 for the second chunk, 2 for the third, and so on).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_chunk_data` is 0.
+    pub fn split(message_id: u64, payload: &[u8], max_chunk_data: usize) -> Vec<Chunk> {
+        assert!(max_chunk_data > 0, "max_chunk_data must be greater than 0, got {}", max_chunk_data);
+
+        let num_chunks = if payload.is_empty() {
+            1
+        } else {
+            (payload.len() + max_chunk_data - 1) / max_chunk_data
+        };
+        let message_length = payload.len() as u64;
+
+        (0..num_chunks)
+            .map(|i| {
+                let start = i * max_chunk_data;
+                let end = std::cmp::min(start + max_chunk_data, payload.len());
+                let segment = payload[start..end].to_vec();
+                let chunk_x = if i == 0 {
+                    ((num_chunks as u32) << 1) | 1
+                } else {
+                    (i as u32) << 1
+                };
+
+                Chunk {
+                    length: (24 + segment.len()) as u32,
+                    chunk_x,
+                    message_id,
+                    message_length,
+                    data: segment,
+                }
+            })
+            .collect()
+    }
+
     pub fn from_bytes(data: &[u8]) -> Self {
         let mut buf: [u8; 4] = Default::default();
         buf.copy_from_slice(&data[0..4]);
@@ -93,14 +141,247 @@ impl Chunk {
         }
     }
 
+    // `chunk_x` without its first-chunk bit (shifted right by 1): the total chunk count on the
+    // first chunk of a message, or the 0-indexed position of the chunk within its message
+    // otherwise (1 for the second chunk).
     fn get_chunk(&self) -> u32 {
-        assert!(self.is_first_chunk());
         self.chunk_x >> 1
     }
 
     fn is_first_chunk(&self) -> bool {
          (self.chunk_x & 0x01) == 1
     }
+
+    // Real chunk counts/indices never come close to needing the top bit of `chunk_x` (they're
+    // shifted left by only 1), so it's reserved as a flag marking this chunk as an in-band
+    // error frame rather than a chunk of message data. This can only be checked on the header,
+    // never on `data`, since a data chunk's payload is an arbitrary slice of the message body
+    // and so can contain any byte sequence.
+    const ERROR_FLAG: u32 = 1 << 31;
+
+    /// Builds a single chunk that aborts `message_id`'s stream, e.g. because a cursor died
+    /// partway through a multi-response stream. `error_code` mirrors `ResponseMessage`'s
+    /// HTTP-style `responseCode`; `error_body`, if present, is the VelocyPack-encoded error
+    /// object describing the failure.
+    pub fn error(message_id: u64, error_code: u32, error_body: Option<Vec<u8>>) -> Self {
+        let mut data = Vec::with_capacity(4 + error_body.as_ref().map_or(0, Vec::len));
+        data.extend_from_slice(&error_code.to_le_bytes());
+        if let Some(body) = error_body {
+            data.extend(body);
+        }
+
+        Self {
+            length: (24 + data.len()) as u32,
+            chunk_x: Self::encode_chunk_x(0, 1) | Self::ERROR_FLAG,
+            message_id,
+            message_length: data.len() as u64,
+            data,
+        }
+    }
+
+    fn is_error(&self) -> bool {
+        (self.chunk_x & Self::ERROR_FLAG) != 0
+    }
+
+    // If this chunk is an in-band error frame, returns its error code and (possibly empty)
+    // VelocyPack error body, or an error if it's flagged as one but too short to hold a code.
+    fn as_error(&self) -> Option<Result<(u32, &[u8]), AssemblyError>> {
+        if !self.is_error() {
+            return None;
+        }
+        if self.data.len() < 4 {
+            return Some(Err(AssemblyError::MalformedErrorFrame {
+                message_id: self.message_id,
+                len: self.data.len(),
+            }));
+        }
+        let mut code_bytes: [u8; 4] = Default::default();
+        code_bytes.copy_from_slice(&self.data[0..4]);
+        Some(Ok((u32::from_le_bytes(code_bytes), &self.data[4..])))
+    }
+}
+
+/// Error returned by [`MessageAssembler`] when a message cannot be (or was never meant to be)
+/// assembled into a complete body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssemblyError {
+    LengthMismatch { message_id: u64, expected: u64, actual: u64 },
+
+    /// The sender aborted this message's stream in-band, e.g. a cursor that died partway
+    /// through a multi-response stream, rather than sending the remaining chunks.
+    Stream { message_id: u64, error_code: u32, error_body: Vec<u8> },
+
+    /// A chunk was flagged as an in-band error frame but was too short to carry an error
+    /// code, which a well-behaved peer never sends.
+    MalformedErrorFrame { message_id: u64, len: usize },
+}
+
+impl std::fmt::Display for AssemblyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AssemblyError::LengthMismatch { message_id, expected, actual } => write!(
+                f,
+                "message {} assembled to {} bytes, expected {}",
+                message_id, actual, expected
+            ),
+            AssemblyError::Stream { message_id, error_code, .. } => write!(
+                f,
+                "message {} aborted with error code {}",
+                message_id, error_code
+            ),
+            AssemblyError::MalformedErrorFrame { message_id, len } => write!(
+                f,
+                "message {} got an error frame with only {} byte(s) of data, need at least 4",
+                message_id, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AssemblyError {}
+
+// Chunks received so far for a single message_id, keyed by their 0-indexed position.
+#[derive(Default)]
+struct PartialMessage {
+    num_chunks: Option<u32>,
+    message_length: Option<u64>,
+    chunks: HashMap<u32, Vec<u8>>,
+}
+
+impl PartialMessage {
+    fn is_complete(&self) -> bool {
+        match self.num_chunks {
+            Some(num_chunks) => (0..num_chunks).all(|i| self.chunks.contains_key(&i)),
+            None => false,
+        }
+    }
+
+    fn assemble(&self, message_id: u64) -> Result<Vec<u8>, AssemblyError> {
+        let num_chunks = self.num_chunks.expect("assemble() called on incomplete message");
+        let mut body = Vec::new();
+        for i in 0..num_chunks {
+            body.extend_from_slice(&self.chunks[&i]);
+        }
+
+        let expected = self.message_length.unwrap_or(0);
+        if body.len() as u64 != expected {
+            return Err(AssemblyError::LengthMismatch { message_id, expected, actual: body.len() as u64 });
+        }
+
+        Ok(body)
+    }
+}
+
+// A single message's outstanding chunks, waiting to be sent.
+struct QueuedMessage {
+    message_id: u64,
+    priority: u8,
+    chunks: std::collections::VecDeque<Chunk>,
+}
+
+/// Queues chunks for multiple in-flight messages and yields them in priority order
+/// (lower `priority` value goes first), round-robining across messages that share the
+/// highest priority so a large multi-chunk transfer can't starve smaller ones.
+#[derive(Default)]
+pub struct SendQueue {
+    messages: std::collections::VecDeque<QueuedMessage>,
+}
+
+impl SendQueue {
+    /// Priority for bulk transfers that should never pre-empt latency-sensitive messages
+    /// enqueued at a lower value.
+    pub const BACKGROUND_PRIORITY: u8 = u8::MAX;
+
+    pub fn new() -> Self {
+        Self { messages: std::collections::VecDeque::new() }
+    }
+
+    pub fn enqueue(&mut self, message_id: u64, chunks: Vec<Chunk>, priority: u8) {
+        self.messages.push_back(QueuedMessage {
+            message_id,
+            priority,
+            chunks: chunks.into(),
+        });
+    }
+
+    /// Convenience for [`enqueue`](Self::enqueue) at [`BACKGROUND_PRIORITY`](Self::BACKGROUND_PRIORITY).
+    pub fn enqueue_background(&mut self, message_id: u64, chunks: Vec<Chunk>) {
+        self.enqueue(message_id, chunks, Self::BACKGROUND_PRIORITY);
+    }
+
+    /// Returns the next chunk to send, taken from the lowest-priority-value message that
+    /// still has chunks queued. Messages at the same priority are served round-robin: each
+    /// one yields a single chunk before any of them yields a second.
+    pub fn next_chunk(&mut self) -> Option<Chunk> {
+        let min_priority = self.messages.iter()
+            .filter(|m| !m.chunks.is_empty())
+            .map(|m| m.priority)
+            .min()?;
+
+        let pos = self.messages.iter()
+            .position(|m| m.priority == min_priority && !m.chunks.is_empty())?;
+
+        let mut message = self.messages.remove(pos).unwrap();
+        let chunk = message.chunks.pop_front();
+        if !message.chunks.is_empty() {
+            self.messages.push_back(message);
+        }
+        chunk
+    }
+}
+
+/// Rebuilds complete message bodies from the `Chunk`s that make them up, the receive-side
+/// counterpart to [`Chunk::split`].
+#[derive(Default)]
+pub struct MessageAssembler {
+    partials: HashMap<u64, PartialMessage>,
+}
+
+impl MessageAssembler {
+    pub fn new() -> Self {
+        Self { partials: HashMap::new() }
+    }
+
+    /// Feeds a received chunk in. Returns `Ok(Some((message_id, body)))` once every chunk of
+    /// the chunk's message has arrived, `Ok(None)` while chunks are still outstanding, and
+    /// `Err` if the assembled body doesn't match the message length declared by the first
+    /// chunk, or if the sender aborted this message's stream with an in-band error chunk. An
+    /// error terminates the message immediately, without waiting for any remaining chunks.
+    /// The `message_id` on the success path lets a caller with several messages in flight
+    /// (e.g. fed from a `SendQueue`) tell which request a completed body belongs to.
+    pub fn push(&mut self, chunk: Chunk) -> Result<Option<(u64, Vec<u8>)>, AssemblyError> {
+        let message_id = chunk.message_id;
+
+        match chunk.as_error() {
+            Some(Ok((error_code, error_body))) => {
+                self.partials.remove(&message_id);
+                return Err(AssemblyError::Stream { message_id, error_code, error_body: error_body.to_vec() });
+            }
+            Some(Err(e)) => {
+                self.partials.remove(&message_id);
+                return Err(e);
+            }
+            None => {}
+        }
+
+        let partial = self.partials.entry(message_id).or_insert_with(PartialMessage::default);
+
+        let index = if chunk.is_first_chunk() {
+            partial.num_chunks = Some(chunk.chunk_x >> 1);
+            partial.message_length = Some(chunk.message_length);
+            0
+        } else {
+            chunk.chunk_x >> 1
+        };
+        partial.chunks.insert(index, chunk.data);
+
+        if partial.is_complete() {
+            let partial = self.partials.remove(&message_id).unwrap();
+            partial.assemble(message_id).map(|body| Some((message_id, body)))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -151,6 +432,38 @@ impl RequestMessage {
 
         velocypack::to_bytes(&arr)
     }
+
+    /// Serializes this request and splits it into chunks ready to send, assigning it a
+    /// fresh message id from `ids`. Returns that id alongside the chunks so the caller can
+    /// correlate a later response back to this request.
+    pub fn into_chunks(&self, ids: &MessageIdGenerator, max_chunk_data: usize) -> velocypack::Result<(u64, Vec<Chunk>)> {
+        let message_id = ids.next();
+        let payload = self.to_bytes()?;
+        Ok((message_id, Chunk::split(message_id, &payload, max_chunk_data)))
+    }
+}
+
+/// Generates unique, monotonically increasing message ids scoped to a single connection.
+/// Zero is reserved to mean "not set", so ids start at 1.
+pub struct MessageIdGenerator {
+    next: std::sync::atomic::AtomicU64,
+}
+
+impl MessageIdGenerator {
+    pub fn new() -> Self {
+        Self { next: std::sync::atomic::AtomicU64::new(1) }
+    }
+
+    /// Returns the next id from this generator. Never returns 0.
+    pub fn next(&self) -> u64 {
+        self.next.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+impl Default for MessageIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Default for RequestMessage {
@@ -166,3 +479,263 @@ impl Default for RequestMessage {
         }
     }
 }
+
+/// An authentication handshake message, sent before issuing requests on a connection that
+/// requires it.
+pub enum AuthMessage {
+    Plain { version: u32, username: String, password: String },
+    Jwt { version: u32, token: String },
+}
+
+impl AuthMessage {
+    pub fn plain(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Plain { version: 1, username: username.into(), password: password.into() }
+    }
+
+    pub fn jwt(token: impl Into<String>) -> Self {
+        Self::Jwt { version: 1, token: token.into() }
+    }
+
+    pub fn to_bytes(&self) -> velocypack::Result<Vec<u8>> {
+        match self {
+            AuthMessage::Plain { version, username, password } => {
+                let mut arr: Vec<Box<dyn erased_serde::Serialize>> = Vec::with_capacity(5);
+                arr.push(Box::new(version));
+                arr.push(Box::new(MessageType::Authentication as i32));
+                arr.push(Box::new("plain"));
+                arr.push(Box::new(username));
+                arr.push(Box::new(password));
+                velocypack::to_bytes(&arr)
+            }
+            AuthMessage::Jwt { version, token } => {
+                let mut arr: Vec<Box<dyn erased_serde::Serialize>> = Vec::with_capacity(4);
+                arr.push(Box::new(version));
+                arr.push(Box::new(MessageType::Authentication as i32));
+                arr.push(Box::new("jwt"));
+                arr.push(Box::new(token));
+                velocypack::to_bytes(&arr)
+            }
+        }
+    }
+
+    /// Serializes this handshake and splits it into chunks ready to send, assigning it a
+    /// fresh message id from `ids`. Returns that id alongside the chunks so the caller can
+    /// correlate a later response back to this handshake.
+    pub fn into_chunks(&self, ids: &MessageIdGenerator, max_chunk_data: usize) -> velocypack::Result<(u64, Vec<Chunk>)> {
+        let message_id = ids.next();
+        let payload = self.to_bytes()?;
+        Ok((message_id, Chunk::split(message_id, &payload, max_chunk_data)))
+    }
+}
+
+/// A decoded response, as sent back for a `RequestMessage`.
+pub struct ResponseMessage {
+    pub version: u32,
+    pub message_type: MessageType,
+    pub response_code: u32,
+    pub meta: HashMap<String, String>,
+    payload: Vec<u8>,
+}
+
+impl ResponseMessage {
+    /// The bytes of the reassembled response body after the VelocyPack header array.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Deserializes the leading VelocyPack header array
+    /// `[version, type, responseCode, meta]` from a reassembled message body, and keeps the
+    /// remaining bytes as the payload.
+    pub fn from_bytes(body: &[u8]) -> velocypack::Result<ResponseMessage> {
+        // TODO: confirm against the velocypack crate docs that `Deserializer::byte_offset()`
+        // reports exactly the number of bytes consumed by the preceding `deserialize` call
+        // (i.e. the end of the header array within `body`), not e.g. some other Vec<u8>/buffer
+        // offset or an offset into pre-skipped padding. If it isn't precise here, `header_len`
+        // below is wrong and every response silently misparses.
+        let mut de = velocypack::Deserializer::from_slice(body);
+        let (version, raw_type, response_code, meta): (u32, i32, u32, HashMap<String, String>) =
+            serde::Deserialize::deserialize(&mut de)?;
+        let header_len = de.byte_offset();
+
+        // Only `FinalResponse` and `Response` are ever sent back for a request; anything else
+        // is treated as "more data follows".
+        let message_type = if raw_type == MessageType::FinalResponse as i32 {
+            MessageType::FinalResponse
+        } else {
+            MessageType::Response
+        };
+
+        Ok(ResponseMessage {
+            version,
+            message_type,
+            response_code,
+            meta,
+            payload: body[header_len..].to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_then_assemble_round_trips_multi_chunk_payload() {
+        let payload = vec![7u8; 2500];
+        let chunks = Chunk::split(42, &payload, 1000);
+        assert_eq!(chunks.len(), 3);
+
+        let mut assembler = MessageAssembler::new();
+        let mut result = None;
+        for chunk in chunks {
+            result = assembler.push(chunk).unwrap();
+        }
+        assert_eq!(result, Some((42, payload)));
+    }
+
+    #[test]
+    fn split_then_assemble_round_trips_empty_payload() {
+        let chunks = Chunk::split(7, &[], 1000);
+        assert_eq!(chunks.len(), 1);
+
+        let mut assembler = MessageAssembler::new();
+        let result = assembler.push(chunks.into_iter().next().unwrap()).unwrap();
+        assert_eq!(result, Some((7, Vec::new())));
+    }
+
+    #[test]
+    fn send_queue_interleaves_equal_priority_messages() {
+        let mut queue = SendQueue::new();
+        queue.enqueue(1, vec![Chunk::from_data(1, b"a0".to_vec()), Chunk::from_data(1, b"a1".to_vec())], 5);
+        queue.enqueue(2, vec![Chunk::from_data(2, b"b0".to_vec()), Chunk::from_data(2, b"b1".to_vec())], 5);
+
+        let order: Vec<u64> = std::iter::from_fn(|| queue.next_chunk()).map(|c| c.message_id()).collect();
+        assert_eq!(order, vec![1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn send_queue_prefers_higher_priority_message() {
+        let mut queue = SendQueue::new();
+        queue.enqueue(1, vec![Chunk::from_data(1, b"bulk".to_vec())], SendQueue::BACKGROUND_PRIORITY);
+        queue.enqueue(2, vec![Chunk::from_data(2, b"urgent".to_vec())], 0);
+
+        assert_eq!(queue.next_chunk().unwrap().message_id(), 2);
+        assert_eq!(queue.next_chunk().unwrap().message_id(), 1);
+        assert!(queue.next_chunk().is_none());
+    }
+
+    #[test]
+    fn error_chunk_short_circuits_message_assembly() {
+        let mut assembler = MessageAssembler::new();
+        let chunks = Chunk::split(9, b"partial payload before things went wrong", 10);
+        assert_eq!(assembler.push(chunks.into_iter().next().unwrap()), Ok(None));
+
+        let result = assembler.push(Chunk::error(9, 500, None));
+        assert_eq!(
+            result,
+            Err(AssemblyError::Stream { message_id: 9, error_code: 500, error_body: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn malformed_error_chunk_returns_error_without_panicking() {
+        let chunk = Chunk {
+            length: 26,
+            chunk_x: (1u32 << 31) | 3, // error flag set, first-and-only chunk
+            message_id: 5,
+            message_length: 2,
+            data: vec![1, 2], // too short to hold a u32 error code
+        };
+
+        let mut assembler = MessageAssembler::new();
+        assert_eq!(
+            assembler.push(chunk),
+            Err(AssemblyError::MalformedErrorFrame { message_id: 5, len: 2 })
+        );
+    }
+
+    #[test]
+    fn message_id_generator_starts_at_one_and_is_strictly_monotonic() {
+        let ids = MessageIdGenerator::new();
+        let first = ids.next();
+        assert_eq!(first, 1);
+
+        let mut previous = first;
+        for _ in 0..100 {
+            let next = ids.next();
+            assert!(next > previous, "ids must be strictly increasing");
+            assert_ne!(next, 0, "0 is reserved for \"not set\"");
+            previous = next;
+        }
+    }
+
+    #[test]
+    fn request_message_into_chunks_returns_assigned_message_id() {
+        let ids = MessageIdGenerator::new();
+        let request = RequestMessage::default();
+
+        let (message_id, chunks) = request.into_chunks(&ids, Chunk::DEFAULT_MAX_CHUNK_DATA).unwrap();
+        assert_eq!(message_id, 1);
+        assert!(chunks.iter().all(|c| c.message_id() == message_id));
+    }
+
+    #[test]
+    fn auth_message_plain_serializes_expected_header_array() {
+        let msg = AuthMessage::plain("alice", "hunter2");
+        let bytes = msg.to_bytes().unwrap();
+
+        let mut de = velocypack::Deserializer::from_slice(&bytes);
+        let (version, message_type, encoding, username, password): (u32, i32, String, String, String) =
+            serde::Deserialize::deserialize(&mut de).unwrap();
+
+        assert_eq!(version, 1);
+        assert_eq!(message_type, MessageType::Authentication as i32);
+        assert_eq!(encoding, "plain");
+        assert_eq!(username, "alice");
+        assert_eq!(password, "hunter2");
+    }
+
+    #[test]
+    fn auth_message_jwt_serializes_expected_header_array() {
+        let msg = AuthMessage::jwt("my-token");
+        let bytes = msg.to_bytes().unwrap();
+
+        let mut de = velocypack::Deserializer::from_slice(&bytes);
+        let (version, message_type, encoding, token): (u32, i32, String, String) =
+            serde::Deserialize::deserialize(&mut de).unwrap();
+
+        assert_eq!(version, 1);
+        assert_eq!(message_type, MessageType::Authentication as i32);
+        assert_eq!(encoding, "jwt");
+        assert_eq!(token, "my-token");
+    }
+
+    #[test]
+    fn auth_message_into_chunks_returns_assigned_message_id() {
+        let ids = MessageIdGenerator::new();
+        let msg = AuthMessage::jwt("my-token");
+
+        let (message_id, chunks) = msg.into_chunks(&ids, Chunk::DEFAULT_MAX_CHUNK_DATA).unwrap();
+        assert_eq!(message_id, 1);
+        assert!(chunks.iter().all(|c| c.message_id() == message_id));
+    }
+
+    #[test]
+    fn response_message_from_bytes_splits_header_from_payload() {
+        let mut meta = HashMap::new();
+        meta.insert("x-request-id".to_owned(), "abc123".to_owned());
+        let header: (u32, i32, u32, HashMap<String, String>) =
+            (1, MessageType::FinalResponse as i32, 200, meta.clone());
+
+        let mut body = velocypack::to_bytes(&header).unwrap();
+        let trailing_payload = b"the rest of the response body";
+        body.extend_from_slice(trailing_payload);
+
+        let response = ResponseMessage::from_bytes(&body).unwrap();
+        assert_eq!(response.version, 1);
+        assert!(matches!(response.message_type, MessageType::FinalResponse));
+        assert_eq!(response.response_code, 200);
+        assert_eq!(response.meta, meta);
+        assert_eq!(response.payload(), trailing_payload);
+    }
+}